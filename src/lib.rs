@@ -28,6 +28,9 @@
 //! }
 //! ```
 //!
+//! With the `alloc` feature enabled, `leak_to_static` provides exactly this, built on top of
+//! the [`IntoStatic`] trait.
+//!
 //! ## Features
 //!
 //! Two convenience methods, [`static_type_id`](`Staticize::static_type_id`) and
@@ -36,12 +39,19 @@
 //! [`TypeId`](`core::any::TypeId`) and name (as a `&'static str`) of the _static_ version of
 //! `T`.
 //!
+//! The [`IntoStatic`] and [`TryIntoStatic`] traits are also provided for converting actual
+//! values (rather than just types) into their `'static` versions. [`IntoStatic`] always
+//! succeeds, leaking borrowed data onto the heap where necessary, while [`TryIntoStatic`] only
+//! succeeds where this is possible without leaking, such as turning an owned `Cow<'a, str>`
+//! into a `Cow<'static, str>`.
+//!
 //! Staticize is completely `no_std`, so it can be used in exotic scenarios where the standard
 //! library is not available, such as embedded devices or in WASM.
 //!
 //! ### `std`
 //!
 //! The `std` feature adds additional impls for `std` types, such as `String`, `Vec`, etc.
+//! Enabling `std` also enables `alloc`.
 //!
 //! ### `alloc`
 //!
@@ -62,17 +72,66 @@ use std::ffi::{OsStr, OsString};
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "alloc")]
+use alloc::borrow::{Cow, ToOwned};
+
+#[cfg(feature = "alloc")]
+use core::borrow::Borrow;
+
 use core::{
-    any::{type_name, TypeId},
+    any::{type_name, Any, TypeId},
+    marker::PhantomData,
     ops::{Bound, ControlFlow},
+    slice::{Iter, IterMut},
     sync::atomic::{
-        AtomicBool, AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicIsize, AtomicU16, AtomicU32,
-        AtomicU64, AtomicU8, AtomicUsize, Ordering,
+        AtomicBool, AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicIsize, AtomicPtr, AtomicU16,
+        AtomicU32, AtomicU64, AtomicU8, AtomicUsize, Ordering,
     },
 };
 
 /// Provides a handy `Static` associated type which should resolve to a `'static` version of
 /// `T` for all `T` that implement [`Staticize`].
+///
+/// Note that `Static` may be unsized, as is the case for `str` and `[T]`, so generic code that
+/// needs to hold a `T::Static` by value must require `T::Static: Sized`:
+///
+/// ```compile_fail,E0277
+/// use staticize::Staticize;
+///
+/// let _: Option<<str as Staticize>::Static> = None;
+/// ```
+///
+/// Naming the `Static` version of a type that does not implement [`Staticize`] is likewise a
+/// compile error:
+///
+/// ```compile_fail,E0277
+/// use staticize::Staticize;
+///
+/// struct Unsupported;
+///
+/// let _: Option<<Unsupported as Staticize>::Static> = None;
+/// ```
+///
+/// When a type is missing an impl, the error points at [`derive_staticize!`] or a manual impl:
+///
+/// ```compile_fail,E0277
+/// use staticize::Staticize;
+///
+/// struct Unsupported;
+///
+/// fn requires_staticize<T: Staticize>() {}
+///
+/// requires_staticize::<Unsupported>();
+/// ```
+///
+/// These examples only check that compilation fails with `E0277`; the wording of the custom
+/// message and notes is not covered by any test.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `Staticize`",
+    label = "no `'static` version of `{Self}` is known",
+    note = "if `{Self}` is already `'static`, try `derive_staticize!({Self})`",
+    note = "otherwise, implement `Staticize` manually with `type Static` set to `{Self}` with all of its lifetimes replaced by `'static`"
+)]
 pub trait Staticize {
     /// A `'static` version of `T`.
     type Static: 'static + ?Sized;
@@ -84,22 +143,397 @@ pub trait Staticize {
 
     /// Returns the type name of the `'static` version of `T`
     fn static_type_name() -> &'static str {
-        &type_name::<Self::Static>()
+        type_name::<Self::Static>()
+    }
+}
+
+/// The `'static` version of `Result<T, E>`.
+pub type StaticResult<T, E> = Result<<T as Staticize>::Static, <E as Staticize>::Static>;
+
+/// Returns the [`TypeId`] of [`StaticResult<T, E>`], i.e. of the `'static` version of
+/// `Result<T, E>`.
+///
+/// This is handy for error-handling frameworks that key handlers by the static `Result` type.
+pub fn static_result_type_id<T: Staticize + ?Sized, E: Staticize + ?Sized>() -> TypeId
+where
+    <T as Staticize>::Static: Sized,
+    <E as Staticize>::Static: Sized,
+{
+    TypeId::of::<StaticResult<T, E>>()
+}
+
+/// Downcasts `any` to a reference to the `'static` version of `T`.
+///
+/// Returns [`None`] if the [`TypeId`] of `any` does not match [`Staticize::static_type_id`].
+/// This makes it possible to recover a value that was type-erased under its static type while
+/// only having a (potentially non-`'static`) `T` at hand, e.g. downcasting a stored
+/// `&'static u32` via a generic `&'a u32` parameter.
+pub fn downcast_static_ref<T: Staticize + ?Sized>(any: &dyn Any) -> Option<&T::Static>
+where
+    <T as Staticize>::Static: Sized,
+{
+    any.downcast_ref::<T::Static>()
+}
+
+/// A map from the static [`TypeId`] of a type to a value of type `V`.
+///
+/// All methods are keyed by [`Staticize::static_type_id`], so types that only differ in their
+/// lifetimes, such as `&'a u8` and `&'b u8`, share the same entry. This codifies the pattern
+/// used by interning maps and plugin systems that associate data with a type.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub struct StaticTypeRegistry<V> {
+    map: std::collections::HashMap<TypeId, V>,
+}
+
+#[cfg(feature = "std")]
+impl<V> StaticTypeRegistry<V> {
+    /// Creates an empty [`StaticTypeRegistry`].
+    pub fn new() -> Self {
+        StaticTypeRegistry {
+            map: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Inserts `value` under the static version of `T`, returning the previous value, if any.
+    pub fn insert<T: Staticize + ?Sized>(&mut self, value: V) -> Option<V> {
+        self.map.insert(T::static_type_id(), value)
+    }
+
+    /// Returns a mutable reference to the value stored under the static version of `T`,
+    /// inserting the result of `f` first if no value is present yet.
+    ///
+    /// `f` is only called if the static version of `T` is not already present.
+    pub fn insert_with<T: Staticize + ?Sized>(&mut self, f: impl FnOnce() -> V) -> &mut V {
+        self.entry::<T>().or_insert_with(f)
+    }
+
+    /// Returns the [`Entry`](`std::collections::hash_map::Entry`) for the static version of
+    /// `T`, for in-place manipulation.
+    pub fn entry<T: Staticize + ?Sized>(
+        &mut self,
+    ) -> std::collections::hash_map::Entry<'_, TypeId, V> {
+        self.map.entry(T::static_type_id())
+    }
+
+    /// Returns a reference to the value stored under the static version of `T`, if any.
+    pub fn get<T: Staticize + ?Sized>(&self) -> Option<&V> {
+        self.map.get(&T::static_type_id())
+    }
+
+    /// Returns `true` if a value is stored under the static version of `T`.
+    pub fn contains<T: Staticize + ?Sized>(&self) -> bool {
+        self.map.contains_key(&T::static_type_id())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V> Default for StaticTypeRegistry<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A type-erased heap allocation holding the `'static` version of some `T`, along with its
+/// type name.
+///
+/// Values are stored and recovered by their static type, so a value stored via
+/// [`StaticBox::new::<&'a u8>`](`StaticBox::new`) can be recovered via
+/// [`StaticBox::get::<&'b u8>`](`StaticBox::get`).
+#[cfg(feature = "alloc")]
+pub struct StaticBox {
+    value: alloc::boxed::Box<dyn Any>,
+    type_name: &'static str,
+}
+
+#[cfg(feature = "alloc")]
+impl StaticBox {
+    /// Allocates `value`, the `'static` version of `T`, on the heap.
+    pub fn new<T: Staticize + ?Sized>(value: T::Static) -> Self
+    where
+        <T as Staticize>::Static: Sized,
+    {
+        StaticBox {
+            value: alloc::boxed::Box::new(value),
+            type_name: T::static_type_name(),
+        }
+    }
+
+    /// Returns a reference to the stored value if it is the `'static` version of `T`, and
+    /// [`None`] otherwise.
+    pub fn get<T: Staticize + ?Sized>(&self) -> Option<&T::Static>
+    where
+        <T as Staticize>::Static: Sized,
+    {
+        downcast_static_ref::<T>(&*self.value)
+    }
+
+    /// Returns the type name of the stored value.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Debug for StaticBox {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("StaticBox")
+            .field("type_name", &self.type_name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Converts a `T` into the `'static` version of `T`.
+///
+/// Where [`Staticize`] only maps types, [`IntoStatic`] converts values. Values that don't
+/// borrow anything, such as primitives or a `String`, are simply moved, while borrowed data is
+/// cloned and leaked onto the heap, so the conversion always succeeds. For example, with the
+/// `alloc` feature enabled, `(&'a u8).into_static()` returns a `&'static u8` pointing to a
+/// leaked copy of the original value. See [`TryIntoStatic`] for conversions that never leak.
+///
+/// Types that are already `'static` can implement this trait via [`derive_into_static!`].
+///
+/// [`IntoStatic`] is deliberately not implemented for `&mut T`, even though `&mut T` implements
+/// [`Staticize`]. Leaking a copy of the referent would silently detach the result from the
+/// original value, so writes through the returned reference would never be observed. Convert a
+/// shared reborrow (`(&*x).into_static()`) or the owned value itself instead:
+///
+#[cfg_attr(feature = "alloc", doc = "```compile_fail")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+/// use staticize::IntoStatic;
+///
+/// let x = &mut 5u8;
+/// let _ = IntoStatic::into_static(&*x); // compiles, leaking a copy of `*x`
+/// let _ = IntoStatic::into_static(x); // error: `&mut u8` does not implement `IntoStatic`
+/// ```
+pub trait IntoStatic: Sized + Staticize<Static: Sized> {
+    /// Converts `self` into its `'static` version, leaking borrowed data if necessary.
+    fn into_static(self) -> Self::Static;
+}
+
+/// Converts `val` into its `'static` version, allocating and leaking any borrowed data.
+///
+/// This is the canonical "allocate and leak" entry point and simply delegates to
+/// [`IntoStatic::into_static`].
+#[cfg(feature = "alloc")]
+pub fn leak_to_static<T: IntoStatic>(val: T) -> T::Static {
+    val.into_static()
+}
+
+/// Converts an [`AtomicPtr<T>`] into an [`AtomicPtr<T::Static>`] pointing to the same address.
+///
+/// This is a no-op at the value level, but unlike [`IntoStatic`] nothing is cloned or leaked,
+/// so the conversion cannot be made safe.
+///
+/// # Safety
+///
+/// The pointer held by `ptr` must either be null or point to a value that is valid for
+/// `'static` as a `T::Static`, i.e. it must not be freed while the returned [`AtomicPtr`] may
+/// still be dereferenced, and all data borrowed by the pointee must be valid for `'static`.
+pub unsafe fn atomic_ptr_into_static<T: Staticize>(ptr: AtomicPtr<T>) -> AtomicPtr<T::Static>
+where
+    <T as Staticize>::Static: Sized,
+{
+    AtomicPtr::new(ptr.into_inner().cast())
+}
+
+/// Determines what a [`TryIntoStatic`] conversion should do when it encounters borrowed data
+/// that can only be made `'static` by cloning it.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BorrowPolicy {
+    /// Fail the conversion, handing the borrowed data back as the error.
+    #[default]
+    Reject,
+    /// Clone the borrowed data so the conversion succeeds.
+    Clone,
+}
+
+/// Fallibly converts a `T` into the `'static` version of `T`.
+///
+/// Unlike the type-level mapping provided by [`Staticize`], this converts actual values, and
+/// some values can only be converted under certain conditions. For example, with the `alloc`
+/// feature enabled, a `Cow::Owned` already owns its data and can become a `Cow<'static, _>` for
+/// free, whereas a `Cow::Borrowed` can only be converted by cloning the data it points to.
+/// Whether that clone is allowed is
+/// controlled by the [`BorrowPolicy`] passed to
+/// [`try_into_static_with`](`TryIntoStatic::try_into_static_with`), while
+/// [`try_into_static`](`TryIntoStatic::try_into_static`) never clones.
+pub trait TryIntoStatic: Sized + Staticize<Static: Sized> {
+    /// The error returned when the conversion fails.
+    type Error;
+
+    /// Attempts to convert `self` into its `'static` version without cloning any borrowed
+    /// data.
+    fn try_into_static(self) -> Result<Self::Static, Self::Error>;
+
+    /// Attempts to convert `self` into its `'static` version, handling borrowed data according
+    /// to the specified [`BorrowPolicy`].
+    ///
+    /// Types that never hold borrowed data can ignore `policy`, which is what the default
+    /// implementation does.
+    fn try_into_static_with(self, policy: BorrowPolicy) -> Result<Self::Static, Self::Error> {
+        let _ = policy;
+        self.try_into_static()
+    }
+}
+
+/// Reinterprets a `&T` as a `&T::Static` in place, without copying or leaking anything.
+///
+/// This is the zero-cost read path for caches holding data that has already been leaked, for
+/// types whose `'static` version differs from `T` only in its lifetimes. Since [`Staticize`] is
+/// a safe trait that allows `Static` to be any type, this trait is implemented separately for
+/// primitives, `str`, slices, references and [`Option`], and references and wrappers only
+/// implement it if their contents do. A compile-time check in
+/// [`as_static`](`StaticizeRef::as_static`) additionally rejects any implementation where the
+/// size or alignment of `T` and `T::Static` differ. `str` and slices cannot be reinterpreted
+/// themselves since they are unsized, but references to them can:
+///
+/// ```
+/// use staticize::StaticizeRef;
+///
+/// static VALUES: [u8; 2] = [1, 2];
+///
+/// let slice: &[u8] = &VALUES;
+/// // SAFETY: `slice` points to a static, so it is valid for `'static`.
+/// let reinterpreted: &&'static [u8] = unsafe { StaticizeRef::as_static(&slice) };
+/// assert_eq!(*reinterpreted, [1, 2]);
+/// ```
+///
+/// A type whose [`Staticize`] impl changes its layout does not implement [`StaticizeRef`], and
+/// neither do references to it:
+///
+/// ```compile_fail,E0277
+/// use staticize::{Staticize, StaticizeRef};
+///
+/// struct Small(u8);
+///
+/// impl Staticize for Small {
+///     type Static = [u64; 64];
+/// }
+///
+/// let small = &Small(1);
+/// let _ = unsafe { StaticizeRef::as_static(&small) };
+/// ```
+///
+/// # Safety
+///
+/// Implementors must guarantee that `Self` and `Self::Static` differ only in their lifetimes,
+/// so that they have identical layouts and, for unsized types, identical pointer metadata.
+pub unsafe trait StaticizeRef: Staticize {
+    /// Reinterprets `self` as a reference to its `'static` version. The returned reference
+    /// points to the same address as `self`.
+    ///
+    /// # Safety
+    ///
+    /// All data borrowed by `self` must actually be valid for `'static`, such as when it has
+    /// been leaked, since the returned value claims to only borrow `'static` data.
+    ///
+    /// In particular, references into arenas (such as those handed out by `typed-arena` or
+    /// `bumpalo`) must never be reinterpreted this way, since the arena frees the data when it
+    /// is dropped. Use [`IntoStatic`] instead, which copies the data out of the arena.
+    unsafe fn as_static(&self) -> &Self::Static
+    where
+        Self: Sized,
+        <Self as Staticize>::Static: Sized,
+    {
+        const {
+            assert!(
+                core::mem::size_of::<Self>() == core::mem::size_of::<Self::Static>()
+                    && core::mem::align_of::<Self>() == core::mem::align_of::<Self::Static>(),
+                "`Self` and `Self::Static` must have the same layout"
+            )
+        };
+        // SAFETY: `Self` and `Self::Static` only differ in lifetimes per the trait contract,
+        // and the caller guarantees that all borrowed data is valid for `'static`.
+        unsafe { &*(self as *const Self).cast::<Self::Static>() }
     }
 }
 
-impl<'a, T: ?Sized> Staticize for &'a T
+/// Implements [`StaticizeRef`] for types that are their own static version.
+macro_rules! derive_staticize_ref {
+    ($($typ:ty),*) => {
+        $(
+            // SAFETY: the bound ensures that `Self::Static` is `Self`.
+            unsafe impl StaticizeRef for $typ where $typ: Staticize<Static = $typ> {}
+        )*
+    };
+}
+
+derive_staticize_ref!(
+    str,
+    char,
+    bool,
+    usize,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    f32,
+    f64,
+    ()
+);
+
+// SAFETY: `[T::Static]` has the same layout and length metadata as `[T]`, since
+// `T: StaticizeRef` guarantees that `T::Static` only differs from `T` in lifetimes.
+unsafe impl<T: StaticizeRef> StaticizeRef for [T] where <T as Staticize>::Static: Sized {}
+
+// SAFETY: `&'static T::Static` has the same layout as `&T`, including any pointer metadata,
+// since `T: StaticizeRef` guarantees that `T::Static` only differs from `T` in lifetimes.
+unsafe impl<T: ?Sized + StaticizeRef> StaticizeRef for &T {}
+
+// SAFETY: `Option<T::Static>` only differs from `Option<T>` in lifetimes since `T: StaticizeRef`.
+unsafe impl<T: StaticizeRef> StaticizeRef for Option<T> where <T as Staticize>::Static: Sized {}
+
+impl<T: ?Sized> Staticize for &T
 where
     T: Staticize,
 {
     type Static = &'static T::Static;
 }
 
-impl<'a, T: Staticize> Staticize for &'a [T]
+impl<T: ?Sized> Staticize for &mut T
+where
+    T: Staticize,
+{
+    type Static = &'static mut T::Static;
+}
+
+impl<T: Staticize> Staticize for [T]
+where
+    <T as Staticize>::Static: Sized,
+{
+    type Static = [T::Static];
+}
+
+/// Arrays of elements that are already `'static`, such as the ubiquitous `[u8; 32]`, are
+/// guaranteed to be their own static version, i.e. `<[u8; N] as Staticize>::Static` is
+/// `[u8; N]` for every `N`.
+impl<T: Staticize, const N: usize> Staticize for [T; N]
+where
+    <T as Staticize>::Static: Sized,
+{
+    type Static = [T::Static; N];
+}
+
+impl<T: Staticize> Staticize for Iter<'_, T>
+where
+    <T as Staticize>::Static: Sized,
+{
+    type Static = Iter<'static, T::Static>;
+}
+
+impl<T: Staticize> Staticize for IterMut<'_, T>
 where
     <T as Staticize>::Static: Sized,
 {
-    type Static = &'static [T::Static];
+    type Static = IterMut<'static, T::Static>;
 }
 
 impl<T: Staticize> Staticize for Option<T>
@@ -109,6 +543,12 @@ where
     type Static = Option<T::Static>;
 }
 
+impl<T: IntoStatic> IntoStatic for Option<T> {
+    fn into_static(self) -> Self::Static {
+        self.map(IntoStatic::into_static)
+    }
+}
+
 impl<T: Staticize, E: Staticize> Staticize for Result<T, E>
 where
     <T as Staticize>::Static: Sized,
@@ -117,6 +557,13 @@ where
     type Static = Result<T::Static, E::Static>;
 }
 
+impl<T: IntoStatic, E: IntoStatic> IntoStatic for Result<T, E> {
+    fn into_static(self) -> Self::Static {
+        self.map(IntoStatic::into_static)
+            .map_err(IntoStatic::into_static)
+    }
+}
+
 impl<B: Staticize, C: Staticize> Staticize for ControlFlow<B, C>
 where
     <B as Staticize>::Static: Sized,
@@ -125,6 +572,15 @@ where
     type Static = ControlFlow<B::Static, C::Static>;
 }
 
+impl<B: IntoStatic, C: IntoStatic> IntoStatic for ControlFlow<B, C> {
+    fn into_static(self) -> Self::Static {
+        match self {
+            ControlFlow::Continue(c) => ControlFlow::Continue(c.into_static()),
+            ControlFlow::Break(b) => ControlFlow::Break(b.into_static()),
+        }
+    }
+}
+
 impl<T: Staticize> Staticize for Bound<T>
 where
     <T as Staticize>::Static: Sized,
@@ -132,168 +588,833 @@ where
     type Static = Bound<T::Static>;
 }
 
-#[cfg(all(feature = "alloc", not(feature = "std")))]
-impl<T: Staticize> Staticize for alloc::vec::Vec<T>
+impl<T: IntoStatic> IntoStatic for Bound<T> {
+    fn into_static(self) -> Self::Static {
+        self.map(IntoStatic::into_static)
+    }
+}
+
+impl<T: Staticize> Staticize for core::cmp::Reverse<T>
 where
     <T as Staticize>::Static: Sized,
 {
-    type Static = alloc::vec::Vec<T::Static>;
+    type Static = core::cmp::Reverse<T::Static>;
 }
 
-#[cfg(all(feature = "alloc", not(feature = "std")))]
-impl<K: Staticize, V: Staticize> Staticize for alloc::collections::BTreeMap<K, V>
+impl<T: Staticize> Staticize for core::future::Ready<T>
 where
-    <K as Staticize>::Static: Sized,
-    <V as Staticize>::Static: Sized,
+    <T as Staticize>::Static: Sized,
 {
-    type Static = alloc::collections::BTreeMap<K::Static, V::Static>;
+    type Static = core::future::Ready<T::Static>;
 }
 
-#[cfg(all(feature = "alloc", not(feature = "std")))]
-impl<T: Staticize> Staticize for alloc::collections::BTreeSet<T>
+impl<T: Staticize> Staticize for core::future::Pending<T>
 where
     <T as Staticize>::Static: Sized,
 {
-    type Static = alloc::collections::BTreeSet<T::Static>;
+    type Static = core::future::Pending<T::Static>;
 }
 
-#[cfg(all(feature = "alloc", not(feature = "std")))]
-impl<T: Staticize> Staticize for alloc::collections::BinaryHeap<T>
+impl<I: Staticize> Staticize for core::iter::Rev<I>
 where
-    <T as Staticize>::Static: Sized,
+    <I as Staticize>::Static: Sized,
 {
-    type Static = alloc::collections::BinaryHeap<T::Static>;
+    type Static = core::iter::Rev<I::Static>;
 }
 
-#[cfg(all(feature = "alloc", not(feature = "std")))]
-impl<T: Staticize> Staticize for alloc::collections::LinkedList<T>
+impl<I: Staticize> Staticize for core::iter::Cloned<I>
 where
-    <T as Staticize>::Static: Sized,
+    <I as Staticize>::Static: Sized,
 {
-    type Static = alloc::collections::LinkedList<T::Static>;
+    type Static = core::iter::Cloned<I::Static>;
 }
 
-#[cfg(all(feature = "alloc", not(feature = "std")))]
-impl<T: Staticize> Staticize for alloc::collections::VecDeque<T>
+impl<T: Staticize> Staticize for core::mem::MaybeUninit<T>
 where
     <T as Staticize>::Static: Sized,
 {
-    type Static = alloc::collections::VecDeque<T::Static>;
+    type Static = core::mem::MaybeUninit<T::Static>;
 }
 
-#[cfg(feature = "std")]
-impl<T: Staticize> Staticize for std::vec::Vec<T>
+impl<T: ?Sized + Staticize> Staticize for core::mem::ManuallyDrop<T> {
+    type Static = core::mem::ManuallyDrop<T::Static>;
+}
+
+/// Since closures cannot be named, this only applies when `F` is itself [`Staticize`], such as a
+/// function pointer. Adapters built from closures are not supported.
+impl<I: Staticize, F: Staticize> Staticize for core::iter::Map<I, F>
 where
-    <T as Staticize>::Static: Sized,
+    <I as Staticize>::Static: Sized,
+    <F as Staticize>::Static: Sized,
 {
-    type Static = std::vec::Vec<T::Static>;
+    type Static = core::iter::Map<I::Static, F::Static>;
 }
 
-#[cfg(feature = "std")]
-impl<K: Staticize, V: Staticize> Staticize for std::collections::BTreeMap<K, V>
+/// As with the `Map` impl above, `P` must be [`Staticize`], which rules out closures.
+impl<I: Staticize, P: Staticize> Staticize for core::iter::Filter<I, P>
 where
-    <K as Staticize>::Static: Sized,
-    <V as Staticize>::Static: Sized,
+    <I as Staticize>::Static: Sized,
+    <P as Staticize>::Static: Sized,
 {
-    type Static = std::collections::BTreeMap<K::Static, V::Static>;
+    type Static = core::iter::Filter<I::Static, P::Static>;
 }
 
-#[cfg(feature = "std")]
-impl<T: Staticize> Staticize for std::collections::BTreeSet<T>
+impl<H: Staticize> Staticize for core::hash::BuildHasherDefault<H>
 where
-    <T as Staticize>::Static: Sized,
+    <H as Staticize>::Static: Sized,
 {
-    type Static = std::collections::BTreeSet<T::Static>;
+    type Static = core::hash::BuildHasherDefault<H::Static>;
 }
 
-#[cfg(feature = "std")]
-impl<T: Staticize> Staticize for std::collections::BinaryHeap<T>
+impl<Idx: Staticize> Staticize for core::ops::Range<Idx>
 where
-    <T as Staticize>::Static: Sized,
+    <Idx as Staticize>::Static: Sized,
 {
-    type Static = std::collections::BinaryHeap<T::Static>;
+    type Static = core::ops::Range<Idx::Static>;
 }
 
-#[cfg(feature = "std")]
-impl<T: Staticize> Staticize for std::collections::LinkedList<T>
+impl<Idx: Staticize> Staticize for core::ops::RangeInclusive<Idx>
 where
-    <T as Staticize>::Static: Sized,
+    <Idx as Staticize>::Static: Sized,
 {
-    type Static = std::collections::LinkedList<T::Static>;
+    type Static = core::ops::RangeInclusive<Idx::Static>;
 }
 
-#[cfg(feature = "std")]
-impl<T: Staticize> Staticize for std::collections::VecDeque<T>
+impl<Idx: Staticize> Staticize for core::ops::RangeFrom<Idx>
 where
-    <T as Staticize>::Static: Sized,
+    <Idx as Staticize>::Static: Sized,
 {
-    type Static = std::collections::VecDeque<T::Static>;
+    type Static = core::ops::RangeFrom<Idx::Static>;
 }
 
-/// Used to implement [`Staticize`] for n-sized tuples.
-///
-/// For example, to add support for tuples of size 17, you would write:
-///
-/// ```ignore
-/// derive_staticize_tuples!(A, B, C);
-/// ```
-///
-/// Note though that this would compile-error because [`Staticize`] is already implemented for
-/// tuple sizes up to 16. You can use the macro to implement it for tuples of larger sizes, if
-/// needed. Just make sure to provide `N` unique letters separated by commas as input to the
-/// macro.
-#[macro_export]
-macro_rules! derive_staticize_tuples {
-    ($($ident:ident),*) => {
-        impl<$($ident: Staticize),*> Staticize for ($($ident),*,)
-        where $(<$ident as Staticize>::Static: Sized),*
-        {
-            type Static = ($($ident::Static),*,);
-        }
-    };
+impl<Idx: Staticize> Staticize for core::ops::RangeTo<Idx>
+where
+    <Idx as Staticize>::Static: Sized,
+{
+    type Static = core::ops::RangeTo<Idx::Static>;
 }
 
-derive_staticize_tuples!(A);
-derive_staticize_tuples!(A, B);
-derive_staticize_tuples!(A, B, C);
-derive_staticize_tuples!(A, B, C, D);
-derive_staticize_tuples!(A, B, C, D, E);
-derive_staticize_tuples!(A, B, C, D, E, F);
-derive_staticize_tuples!(A, B, C, D, E, F, G);
-derive_staticize_tuples!(A, B, C, D, E, F, G, H);
-derive_staticize_tuples!(A, B, C, D, E, F, G, H, I);
-derive_staticize_tuples!(A, B, C, D, E, F, G, H, I, J);
-derive_staticize_tuples!(A, B, C, D, E, F, G, H, I, J, K);
-derive_staticize_tuples!(A, B, C, D, E, F, G, H, I, J, K, L);
-derive_staticize_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, M);
-derive_staticize_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, M, N);
-derive_staticize_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O);
-derive_staticize_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P);
+impl<Idx: Staticize> Staticize for core::ops::RangeToInclusive<Idx>
+where
+    <Idx as Staticize>::Static: Sized,
+{
+    type Static = core::ops::RangeToInclusive<Idx::Static>;
+}
 
-/// Implements [`Staticize`] for the specified type.
-///
-/// This will only work for `T: 'static`, and this macro only exists because we cannot do a
-/// blanket impl for `T: 'static` because it would conflict with many of the other impls we
-/// provide.
-#[macro_export]
-macro_rules! derive_staticize {
-    ($typ:ty) => {
-        impl $crate::Staticize for $typ {
-            type Static = $typ;
-        }
-    };
+impl<Idx: IntoStatic> IntoStatic for core::ops::Range<Idx> {
+    fn into_static(self) -> Self::Static {
+        self.start.into_static()..self.end.into_static()
+    }
 }
 
-derive_staticize!(str);
-derive_staticize!(char);
-derive_staticize!(bool);
-derive_staticize!(usize);
-derive_staticize!(isize);
-derive_staticize!(u8);
-derive_staticize!(u16);
-derive_staticize!(u32);
-derive_staticize!(u64);
-derive_staticize!(u128);
+impl<Idx: IntoStatic> IntoStatic for core::ops::RangeInclusive<Idx> {
+    fn into_static(self) -> Self::Static {
+        let (start, end) = self.into_inner();
+        start.into_static()..=end.into_static()
+    }
+}
+
+impl<Idx: IntoStatic> IntoStatic for core::ops::RangeFrom<Idx> {
+    fn into_static(self) -> Self::Static {
+        self.start.into_static()..
+    }
+}
+
+impl<Idx: IntoStatic> IntoStatic for core::ops::RangeTo<Idx> {
+    fn into_static(self) -> Self::Static {
+        ..self.end.into_static()
+    }
+}
+
+impl<Idx: IntoStatic> IntoStatic for core::ops::RangeToInclusive<Idx> {
+    fn into_static(self) -> Self::Static {
+        ..=self.end.into_static()
+    }
+}
+
+impl<T: Staticize> Staticize for core::num::Wrapping<T>
+where
+    <T as Staticize>::Static: Sized,
+{
+    type Static = core::num::Wrapping<T::Static>;
+}
+
+impl<T: IntoStatic> IntoStatic for core::num::Wrapping<T> {
+    fn into_static(self) -> Self::Static {
+        core::num::Wrapping(self.0.into_static())
+    }
+}
+
+impl<T: Staticize> Staticize for core::num::Saturating<T>
+where
+    <T as Staticize>::Static: Sized,
+{
+    type Static = core::num::Saturating<T::Static>;
+}
+
+impl<T: IntoStatic> IntoStatic for core::num::Saturating<T> {
+    fn into_static(self) -> Self::Static {
+        core::num::Saturating(self.0.into_static())
+    }
+}
+
+impl<T: ?Sized + Staticize> Staticize for PhantomData<T> {
+    type Static = PhantomData<T::Static>;
+}
+
+impl<T: ?Sized + Staticize> Staticize for *const T {
+    type Static = *const T::Static;
+}
+
+impl<T: ?Sized + Staticize> Staticize for *mut T {
+    type Static = *mut T::Static;
+}
+
+impl<T: Staticize> Staticize for AtomicPtr<T>
+where
+    <T as Staticize>::Static: Sized,
+{
+    type Static = AtomicPtr<T::Static>;
+}
+
+impl<T: ?Sized + Staticize> Staticize for core::ptr::NonNull<T> {
+    type Static = core::ptr::NonNull<T::Static>;
+}
+
+impl<T: ?Sized + Staticize> Staticize for core::cell::Cell<T> {
+    type Static = core::cell::Cell<T::Static>;
+}
+
+impl<T: IntoStatic> IntoStatic for core::cell::Cell<T> {
+    fn into_static(self) -> Self::Static {
+        core::cell::Cell::new(self.into_inner().into_static())
+    }
+}
+
+impl<T: ?Sized + Staticize> Staticize for core::cell::RefCell<T> {
+    type Static = core::cell::RefCell<T::Static>;
+}
+
+impl<T: IntoStatic> IntoStatic for core::cell::RefCell<T> {
+    fn into_static(self) -> Self::Static {
+        core::cell::RefCell::new(self.into_inner().into_static())
+    }
+}
+
+impl<T: ?Sized + Staticize> Staticize for core::cell::Ref<'_, T> {
+    type Static = core::cell::Ref<'static, T::Static>;
+}
+
+impl<T: ?Sized + Staticize> Staticize for core::cell::RefMut<'_, T> {
+    type Static = core::cell::RefMut<'static, T::Static>;
+}
+
+impl Staticize for core::panic::Location<'_> {
+    type Static = core::panic::Location<'static>;
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized + ToOwned + Staticize> Staticize for Cow<'_, B>
+where
+    <B as Staticize>::Static: ToOwned,
+{
+    type Static = Cow<'static, B::Static>;
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, B> TryIntoStatic for Cow<'a, B>
+where
+    B: ?Sized + ToOwned + Staticize<Static = B> + 'static,
+{
+    type Error = &'a B;
+
+    fn try_into_static(self) -> Result<Self::Static, Self::Error> {
+        self.try_into_static_with(BorrowPolicy::Reject)
+    }
+
+    fn try_into_static_with(self, policy: BorrowPolicy) -> Result<Self::Static, Self::Error> {
+        match (self, policy) {
+            (Cow::Owned(owned), _) => Ok(Cow::Owned(owned)),
+            (Cow::Borrowed(borrowed), BorrowPolicy::Clone) => Ok(Cow::Owned(borrowed.to_owned())),
+            (Cow::Borrowed(borrowed), BorrowPolicy::Reject) => Err(borrowed),
+        }
+    }
+}
+
+/// Always produces a [`Cow::Owned`], cloning the data via [`Cow::into_owned`] if it was
+/// borrowed. Since the owned data is already `'static`, nothing needs to be leaked.
+#[cfg(feature = "alloc")]
+impl<B> IntoStatic for Cow<'_, B>
+where
+    B: ?Sized + ToOwned + Staticize<Static = B> + 'static,
+{
+    fn into_static(self) -> Self::Static {
+        Cow::Owned(self.into_owned())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized + Staticize> Staticize for alloc::boxed::Box<T> {
+    type Static = alloc::boxed::Box<T::Static>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T: IntoStatic> IntoStatic for alloc::boxed::Box<T> {
+    fn into_static(self) -> Self::Static {
+        alloc::boxed::Box::new((*self).into_static())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl IntoStatic for alloc::boxed::Box<str> {
+    fn into_static(self) -> Self {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: IntoStatic> IntoStatic for alloc::boxed::Box<[T]> {
+    fn into_static(self) -> Self::Static {
+        self.into_vec()
+            .into_iter()
+            .map(IntoStatic::into_static)
+            .collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized + Staticize> Staticize for alloc::sync::Arc<T> {
+    type Static = alloc::sync::Arc<T::Static>;
+}
+
+/// Unwraps the [`Arc`](`alloc::sync::Arc`) if it is uniquely owned and clones the inner value
+/// otherwise, then converts the value and wraps it in a new [`Arc`](`alloc::sync::Arc`).
+///
+/// Note that this always detaches the result from the original allocation, even when `T` is
+/// already `'static`, as with `Arc<String>`. The returned [`Arc`](`alloc::sync::Arc`) starts
+/// with a strong count of one and shares nothing with any remaining clones of the original, so
+/// it must not be used where shared ownership of the original allocation matters. For the same
+/// reason `T` must be [`Clone`], which rules out shared-state payloads such as `Arc<Mutex<_>>`.
+/// The already-`'static` `Arc<str>` has its own impl that is the identity conversion.
+#[cfg(feature = "alloc")]
+impl<T: IntoStatic + Clone> IntoStatic for alloc::sync::Arc<T> {
+    fn into_static(self) -> Self::Static {
+        alloc::sync::Arc::new(alloc::sync::Arc::unwrap_or_clone(self).into_static())
+    }
+}
+
+/// [`Arc<str>`](`alloc::sync::Arc`) is already `'static`, so this is the identity conversion and
+/// the reference count is left untouched.
+#[cfg(feature = "alloc")]
+impl IntoStatic for alloc::sync::Arc<str> {
+    fn into_static(self) -> Self {
+        self
+    }
+}
+
+/// Converts each element of the slice into a newly allocated
+/// [`Arc<[T::Static]>`](`alloc::sync::Arc`).
+#[cfg(feature = "alloc")]
+impl<T: IntoStatic + Clone> IntoStatic for alloc::sync::Arc<[T]> {
+    fn into_static(self) -> Self::Static {
+        self.iter().cloned().map(IntoStatic::into_static).collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> IntoStatic for &T
+where
+    T: ?Sized + ToOwned + Staticize,
+    <T as ToOwned>::Owned: IntoStatic,
+    <<T as ToOwned>::Owned as Staticize>::Static: Borrow<T::Static>,
+{
+    fn into_static(self) -> Self::Static {
+        let leaked: &'static _ =
+            alloc::boxed::Box::leak(alloc::boxed::Box::new(self.to_owned().into_static()));
+        leaked.borrow()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Staticize> Staticize for alloc::vec::Vec<T>
+where
+    <T as Staticize>::Static: Sized,
+{
+    type Static = alloc::vec::Vec<T::Static>;
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Staticize, V: Staticize> Staticize for alloc::collections::BTreeMap<K, V>
+where
+    <K as Staticize>::Static: Sized + Ord,
+    <V as Staticize>::Static: Sized,
+{
+    type Static = alloc::collections::BTreeMap<K::Static, V::Static>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Staticize> Staticize for alloc::collections::BTreeSet<T>
+where
+    <T as Staticize>::Static: Sized + Ord,
+{
+    type Static = alloc::collections::BTreeSet<T::Static>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Staticize> Staticize for alloc::collections::BinaryHeap<T>
+where
+    <T as Staticize>::Static: Sized + Ord,
+{
+    type Static = alloc::collections::BinaryHeap<T::Static>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Staticize> Staticize for alloc::collections::LinkedList<T>
+where
+    <T as Staticize>::Static: Sized,
+{
+    type Static = alloc::collections::LinkedList<T::Static>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Staticize> Staticize for alloc::collections::VecDeque<T>
+where
+    <T as Staticize>::Static: Sized,
+{
+    type Static = alloc::collections::VecDeque<T::Static>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T: IntoStatic> IntoStatic for alloc::vec::Vec<T> {
+    fn into_static(self) -> Self::Static {
+        self.into_iter().map(IntoStatic::into_static).collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: IntoStatic> IntoStatic for alloc::collections::VecDeque<T> {
+    fn into_static(self) -> Self::Static {
+        self.into_iter().map(IntoStatic::into_static).collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: IntoStatic> IntoStatic for alloc::collections::LinkedList<T> {
+    fn into_static(self) -> Self::Static {
+        self.into_iter().map(IntoStatic::into_static).collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: IntoStatic, V: IntoStatic> IntoStatic for alloc::collections::BTreeMap<K, V>
+where
+    <K as Staticize>::Static: Ord,
+{
+    fn into_static(self) -> Self::Static {
+        self.into_iter()
+            .map(|(k, v)| (k.into_static(), v.into_static()))
+            .collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: IntoStatic> IntoStatic for alloc::collections::BTreeSet<T>
+where
+    <T as Staticize>::Static: Ord,
+{
+    fn into_static(self) -> Self::Static {
+        self.into_iter().map(IntoStatic::into_static).collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Staticize, V: Staticize, S: Staticize> Staticize for std::collections::HashMap<K, V, S>
+where
+    <K as Staticize>::Static: Sized + Eq + core::hash::Hash,
+    <V as Staticize>::Static: Sized,
+    <S as Staticize>::Static: Sized,
+{
+    type Static = std::collections::HashMap<K::Static, V::Static, S::Static>;
+}
+
+/// Keys and values are converted recursively, leaking borrowed data, and the map is rebuilt
+/// with capacity for all entries up front, so it never rehashes while being filled. It uses a
+/// default-constructed hasher of type `S::Static`. Note that this means any state held by the original hasher is not carried over.
+#[cfg(feature = "std")]
+impl<K: IntoStatic, V: IntoStatic, S: Staticize> IntoStatic for std::collections::HashMap<K, V, S>
+where
+    <K as Staticize>::Static: Eq + core::hash::Hash,
+    <S as Staticize>::Static: Sized + Default + core::hash::BuildHasher,
+{
+    fn into_static(self) -> Self::Static {
+        let mut map =
+            std::collections::HashMap::with_capacity_and_hasher(self.len(), Default::default());
+        map.extend(
+            self.into_iter()
+                .map(|(k, v)| (k.into_static(), v.into_static())),
+        );
+        map
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Staticize, S: Staticize> Staticize for std::collections::HashSet<T, S>
+where
+    <T as Staticize>::Static: Sized + Eq + core::hash::Hash,
+    <S as Staticize>::Static: Sized,
+{
+    type Static = std::collections::HashSet<T::Static, S::Static>;
+}
+
+/// Elements are converted recursively, leaking borrowed data, and the set is rebuilt with
+/// capacity for all elements up front, using a default-constructed hasher of type `S::Static`. The static element type must be `Eq + Hash` so that membership is preserved.
+#[cfg(feature = "std")]
+impl<T: IntoStatic, S: Staticize> IntoStatic for std::collections::HashSet<T, S>
+where
+    <T as Staticize>::Static: Eq + core::hash::Hash,
+    <S as Staticize>::Static: Sized + Default + core::hash::BuildHasher,
+{
+    fn into_static(self) -> Self::Static {
+        let mut set =
+            std::collections::HashSet::with_capacity_and_hasher(self.len(), Default::default());
+        set.extend(self.into_iter().map(IntoStatic::into_static));
+        set
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Staticize> Staticize for std::io::Cursor<T>
+where
+    <T as Staticize>::Static: Sized,
+{
+    type Static = std::io::Cursor<T::Static>;
+}
+
+#[cfg(feature = "std")]
+impl<R: ?Sized + Staticize> Staticize for std::io::BufReader<R> {
+    type Static = std::io::BufReader<R::Static>;
+}
+
+#[cfg(feature = "std")]
+impl<W: ?Sized + Staticize + std::io::Write> Staticize for std::io::BufWriter<W>
+where
+    <W as Staticize>::Static: std::io::Write,
+{
+    type Static = std::io::BufWriter<W::Static>;
+}
+
+#[cfg(feature = "std")]
+impl<T: Staticize> Staticize for std::thread::JoinHandle<T>
+where
+    <T as Staticize>::Static: Sized,
+{
+    type Static = std::thread::JoinHandle<T::Static>;
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized + Staticize> Staticize for std::sync::Mutex<T> {
+    type Static = std::sync::Mutex<T::Static>;
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized + Staticize> Staticize for std::sync::RwLock<T> {
+    type Static = std::sync::RwLock<T::Static>;
+}
+
+#[cfg(feature = "std")]
+impl<T: Staticize> Staticize for std::sync::mpsc::Sender<T>
+where
+    <T as Staticize>::Static: Sized,
+{
+    type Static = std::sync::mpsc::Sender<T::Static>;
+}
+
+#[cfg(feature = "std")]
+impl<T: Staticize> Staticize for std::sync::mpsc::SyncSender<T>
+where
+    <T as Staticize>::Static: Sized,
+{
+    type Static = std::sync::mpsc::SyncSender<T::Static>;
+}
+
+#[cfg(feature = "std")]
+impl<T: Staticize> Staticize for std::sync::mpsc::Receiver<T>
+where
+    <T as Staticize>::Static: Sized,
+{
+    type Static = std::sync::mpsc::Receiver<T::Static>;
+}
+
+/// Used to implement [`Staticize`] for n-sized tuples.
+///
+/// For example, to add support for tuples of size 17, you would write:
+///
+/// ```ignore
+/// derive_staticize_tuples!(A, B, C);
+/// ```
+///
+/// Note though that this would compile-error because [`Staticize`] is already implemented for
+/// tuple sizes up to 16. You can use the macro to implement it for tuples of larger sizes, if
+/// needed. Just make sure to provide `N` unique letters separated by commas as input to the
+/// macro.
+#[macro_export]
+macro_rules! derive_staticize_tuples {
+    ($($ident:ident),*) => {
+        impl<$($ident: Staticize),*> Staticize for ($($ident),*,)
+        where $(<$ident as Staticize>::Static: Sized),*
+        {
+            type Static = ($($ident::Static),*,);
+        }
+    };
+}
+
+derive_staticize_tuples!(A);
+derive_staticize_tuples!(A, B);
+derive_staticize_tuples!(A, B, C);
+derive_staticize_tuples!(A, B, C, D);
+derive_staticize_tuples!(A, B, C, D, E);
+derive_staticize_tuples!(A, B, C, D, E, F);
+derive_staticize_tuples!(A, B, C, D, E, F, G);
+derive_staticize_tuples!(A, B, C, D, E, F, G, H);
+derive_staticize_tuples!(A, B, C, D, E, F, G, H, I);
+derive_staticize_tuples!(A, B, C, D, E, F, G, H, I, J);
+derive_staticize_tuples!(A, B, C, D, E, F, G, H, I, J, K);
+derive_staticize_tuples!(A, B, C, D, E, F, G, H, I, J, K, L);
+derive_staticize_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, M);
+derive_staticize_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, M, N);
+derive_staticize_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O);
+derive_staticize_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P);
+
+/// Used to implement [`IntoStatic`] for n-sized tuples, converting each element in turn.
+///
+/// Works just like [`derive_staticize_tuples!`], and [`IntoStatic`] is likewise already
+/// implemented for tuple sizes up to 16. To support tuples of a larger size, invoke both macros
+/// with the same `N` unique letters.
+#[macro_export]
+macro_rules! derive_into_static_tuples {
+    ($($ident:ident),*) => {
+        impl<$($ident: IntoStatic),*> IntoStatic for ($($ident),*,) {
+            #[allow(non_snake_case)]
+            fn into_static(self) -> Self::Static {
+                let ($($ident),*,) = self;
+                ($($ident.into_static()),*,)
+            }
+        }
+    };
+}
+
+derive_into_static_tuples!(A);
+derive_into_static_tuples!(A, B);
+derive_into_static_tuples!(A, B, C);
+derive_into_static_tuples!(A, B, C, D);
+derive_into_static_tuples!(A, B, C, D, E);
+derive_into_static_tuples!(A, B, C, D, E, F);
+derive_into_static_tuples!(A, B, C, D, E, F, G);
+derive_into_static_tuples!(A, B, C, D, E, F, G, H);
+derive_into_static_tuples!(A, B, C, D, E, F, G, H, I);
+derive_into_static_tuples!(A, B, C, D, E, F, G, H, I, J);
+derive_into_static_tuples!(A, B, C, D, E, F, G, H, I, J, K);
+derive_into_static_tuples!(A, B, C, D, E, F, G, H, I, J, K, L);
+derive_into_static_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, M);
+derive_into_static_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, M, N);
+derive_into_static_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O);
+derive_into_static_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P);
+
+/// Used to implement [`Staticize`] for function pointers taking n arguments.
+///
+/// For example, to add support for function pointers taking 17 arguments, you would write:
+///
+/// ```ignore
+/// derive_staticize_fns!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q);
+/// ```
+///
+/// [`Staticize`] is already implemented for function pointers taking up to 16 arguments. The
+/// return type is always named `R`, so make sure not to use `R` as one of the argument letters.
+///
+/// Note that these impls only cover function pointers whose argument lifetimes are concrete,
+/// such as `fn(&'a u8)`, and not higher-ranked ones such as `for<'b> fn(&'b u8)`.
+#[macro_export]
+macro_rules! derive_staticize_fns {
+    ($($ident:ident),*) => {
+        impl<R: Staticize, $($ident: Staticize),*> Staticize for fn($($ident),*) -> R
+        where
+            <R as Staticize>::Static: Sized,
+            $(<$ident as Staticize>::Static: Sized),*
+        {
+            type Static = fn($($ident::Static),*) -> R::Static;
+        }
+    };
+}
+
+derive_staticize_fns!();
+derive_staticize_fns!(A);
+derive_staticize_fns!(A, B);
+derive_staticize_fns!(A, B, C);
+derive_staticize_fns!(A, B, C, D);
+derive_staticize_fns!(A, B, C, D, E);
+derive_staticize_fns!(A, B, C, D, E, F);
+derive_staticize_fns!(A, B, C, D, E, F, G);
+derive_staticize_fns!(A, B, C, D, E, F, G, H);
+derive_staticize_fns!(A, B, C, D, E, F, G, H, I);
+derive_staticize_fns!(A, B, C, D, E, F, G, H, I, J);
+derive_staticize_fns!(A, B, C, D, E, F, G, H, I, J, K);
+derive_staticize_fns!(A, B, C, D, E, F, G, H, I, J, K, L);
+derive_staticize_fns!(A, B, C, D, E, F, G, H, I, J, K, L, M);
+derive_staticize_fns!(A, B, C, D, E, F, G, H, I, J, K, L, M, N);
+derive_staticize_fns!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O);
+derive_staticize_fns!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P);
+
+/// Implements [`Staticize`] for the specified type.
+///
+/// This will only work for `T: 'static`, and this macro only exists because we cannot do a
+/// blanket impl for `T: 'static` because it would conflict with many of the other impls we
+/// provide.
+#[macro_export]
+macro_rules! derive_staticize {
+    ($typ:ty) => {
+        impl $crate::Staticize for $typ {
+            type Static = $typ;
+        }
+    };
+}
+
+/// Builds a dispatch table mapping the static [`TypeId`] of each specified type to a handler.
+///
+/// The macro expands to an array of `(TypeId, handler)` pairs, where each [`TypeId`] is obtained
+/// via [`Staticize::static_type_id`], so a handler registered under `&'a u8` is found when
+/// looking up the static [`TypeId`] of any `&'b u8`. The first argument is the function pointer
+/// type that every handler is cast to, so all handlers must share that signature:
+///
+/// ```ignore
+/// fn handle_u8(val: &dyn Any) -> &'static str { "u8" }
+/// fn handle_str(val: &dyn Any) -> &'static str { "str" }
+///
+/// let table = static_dispatch_table!(
+///     fn(&dyn Any) -> &'static str;
+///     &u8 => handle_u8,
+///     &str => handle_str,
+/// );
+/// let handler = table
+///     .iter()
+///     .find(|(id, _)| *id == <&u8>::static_type_id())
+///     .map(|(_, handler)| handler);
+/// ```
+///
+/// Note that the table is built at runtime, since [`TypeId::of`] cannot yet be called in const
+/// contexts.
+#[macro_export]
+macro_rules! static_dispatch_table {
+    ($handler_ty:ty; $($typ:ty => $handler:expr),* $(,)?) => {
+        [$((<$typ as $crate::Staticize>::static_type_id(), $handler as $handler_ty)),*]
+    };
+}
+
+/// Declares a single-field tuple struct (newtype) and implements [`Staticize`] for it.
+///
+/// The newtype may carry at most one lifetime, which is rewritten to `'static`. The field type
+/// must implement [`Staticize`], and its static version is forwarded as the field of the static
+/// newtype, so the field may only borrow for the newtype's lifetime. Attributes and visibility
+/// modifiers are passed through to the generated struct:
+///
+/// ```ignore
+/// derive_staticize_newtype!(#[derive(Debug)] pub struct Meters(pub f64));
+/// derive_staticize_newtype!(struct Id<'a>(&'a str));
+///
+/// // `Id<'a>` now staticizes to `Id<'static>`
+/// ```
+///
+/// This covers the common newtype case without having to write the impl by hand.
+#[macro_export]
+macro_rules! derive_staticize_newtype {
+    ($(#[$attr:meta])* $vis:vis struct $name:ident($field_vis:vis $inner:ty) $(;)?) => {
+        $(#[$attr])*
+        $vis struct $name($field_vis $inner);
+
+        impl $crate::Staticize for $name {
+            type Static = $name;
+        }
+
+        const _: () = {
+            #[allow(dead_code)]
+            fn forward(val: $name) -> <$inner as $crate::Staticize>::Static {
+                val.0
+            }
+        };
+    };
+    ($(#[$attr:meta])* $vis:vis struct $name:ident<$lt:lifetime>($field_vis:vis $inner:ty) $(;)?) => {
+        $(#[$attr])*
+        $vis struct $name<$lt>($field_vis $inner);
+
+        impl<$lt> $crate::Staticize for $name<$lt>
+        where
+            $inner: $crate::Staticize,
+        {
+            type Static = $name<'static>;
+        }
+
+        const _: () = {
+            #[allow(dead_code)]
+            fn forward<$lt>(val: $name<'static>) -> <$inner as $crate::Staticize>::Static {
+                val.0
+            }
+        };
+    };
+}
+
+/// Implements [`IntoStatic`] for the specified type as the identity conversion.
+///
+/// Like [`derive_staticize!`], this will only work for `T: 'static`, and the type must already
+/// implement [`Staticize`] with `Static = T`.
+#[macro_export]
+macro_rules! derive_into_static {
+    ($typ:ty) => {
+        impl $crate::IntoStatic for $typ {
+            fn into_static(self) -> Self {
+                self
+            }
+        }
+    };
+}
+
+/// Implements [`Staticize`] for trait objects of the specified trait.
+///
+/// The generated impl maps `dyn MyTrait + 'a` to `dyn MyTrait + 'static`, which in turn lets
+/// wrappers such as `Box<dyn MyTrait + 'a>` and `&'a (dyn MyTrait + 'a)` resolve to their
+/// `'static` forms. This is handy for plugin systems that store boxed trait objects carrying a
+/// lifetime:
+///
+/// ```ignore
+/// trait Plugin {
+///     fn name(&self) -> &str;
+/// }
+///
+/// derive_staticize_dyn!(dyn Plugin);
+///
+/// // `Box<dyn Plugin + 'a>` now staticizes to `Box<dyn Plugin + 'static>`
+/// ```
+///
+/// The trait must be dyn compatible (object safe), since otherwise `dyn MyTrait` is not a valid
+/// type. Because of the orphan rules, the trait must also be defined in the crate invoking the
+/// macro.
+#[macro_export]
+macro_rules! derive_staticize_dyn {
+    (dyn $trait:path) => {
+        impl<'a> $crate::Staticize for dyn $trait + 'a {
+            type Static = dyn $trait + 'static;
+        }
+    };
+}
+
+derive_staticize!(str);
+derive_staticize!(char);
+derive_staticize!(bool);
+derive_staticize!(usize);
+derive_staticize!(isize);
+derive_staticize!(u8);
+derive_staticize!(u16);
+derive_staticize!(u32);
+derive_staticize!(u64);
+derive_staticize!(u128);
 derive_staticize!(i8);
 derive_staticize!(i16);
 derive_staticize!(i32);
@@ -302,6 +1423,12 @@ derive_staticize!(i128);
 derive_staticize!(f32);
 derive_staticize!(f64);
 derive_staticize!(());
+derive_staticize!(core::convert::Infallible);
+derive_staticize!(core::cmp::Ordering);
+derive_staticize!(core::ops::RangeFull);
+derive_staticize!(core::time::Duration);
+derive_staticize!(core::ffi::CStr);
+derive_staticize!(TypeId);
 
 derive_staticize!(Ordering);
 derive_staticize!(AtomicBool);
@@ -316,11 +1443,84 @@ derive_staticize!(AtomicI64);
 derive_staticize!(AtomicIsize);
 derive_staticize!(AtomicUsize);
 
-#[cfg(all(feature = "alloc", not(feature = "std")))]
+derive_staticize!(core::net::IpAddr);
+derive_staticize!(core::net::Ipv4Addr);
+derive_staticize!(core::net::Ipv6Addr);
+derive_staticize!(core::net::SocketAddr);
+derive_staticize!(core::net::SocketAddrV4);
+derive_staticize!(core::net::SocketAddrV6);
+derive_staticize!(core::net::AddrParseError);
+
+derive_into_static!(char);
+derive_into_static!(bool);
+derive_into_static!(usize);
+derive_into_static!(isize);
+derive_into_static!(u8);
+derive_into_static!(u16);
+derive_into_static!(u32);
+derive_into_static!(u64);
+derive_into_static!(u128);
+derive_into_static!(i8);
+derive_into_static!(i16);
+derive_into_static!(i32);
+derive_into_static!(i64);
+derive_into_static!(i128);
+derive_into_static!(f32);
+derive_into_static!(f64);
+derive_into_static!(());
+derive_into_static!(core::convert::Infallible);
+derive_into_static!(core::cmp::Ordering);
+derive_into_static!(core::ops::RangeFull);
+derive_into_static!(core::time::Duration);
+derive_into_static!(TypeId);
+
+derive_into_static!(Ordering);
+derive_into_static!(AtomicBool);
+derive_into_static!(AtomicU8);
+derive_into_static!(AtomicU16);
+derive_into_static!(AtomicU32);
+derive_into_static!(AtomicU64);
+derive_into_static!(AtomicI8);
+derive_into_static!(AtomicI16);
+derive_into_static!(AtomicI32);
+derive_into_static!(AtomicI64);
+derive_into_static!(AtomicIsize);
+derive_into_static!(AtomicUsize);
+
+derive_into_static!(core::net::IpAddr);
+derive_into_static!(core::net::Ipv4Addr);
+derive_into_static!(core::net::Ipv6Addr);
+derive_into_static!(core::net::SocketAddr);
+derive_into_static!(core::net::SocketAddrV4);
+derive_into_static!(core::net::SocketAddrV6);
+derive_into_static!(core::net::AddrParseError);
+
+#[cfg(feature = "alloc")]
 derive_staticize!(alloc::string::String);
 
+#[cfg(feature = "alloc")]
+derive_into_static!(alloc::string::String);
+
+#[cfg(feature = "alloc")]
+derive_staticize!(alloc::ffi::CString);
+
+#[cfg(feature = "alloc")]
+derive_into_static!(alloc::ffi::CString);
+
+#[cfg(feature = "std")]
+derive_staticize!(std::hash::RandomState);
+
+#[cfg(feature = "std")]
+derive_staticize!(std::hash::DefaultHasher);
+
+#[cfg(feature = "std")]
+derive_staticize!(std::net::TcpStream);
+
+#[cfg(feature = "std")]
+derive_staticize!(std::net::TcpListener);
+
 #[cfg(feature = "std")]
-derive_staticize!(String);
+derive_staticize!(std::net::UdpSocket);
 
 #[cfg(feature = "std")]
 derive_staticize!(Path);
@@ -328,8 +1528,999 @@ derive_staticize!(Path);
 #[cfg(feature = "std")]
 derive_staticize!(PathBuf);
 
+#[cfg(feature = "std")]
+derive_into_static!(PathBuf);
+
 #[cfg(feature = "std")]
 derive_staticize!(OsStr);
 
 #[cfg(feature = "std")]
 derive_staticize!(OsString);
+
+#[cfg(feature = "std")]
+derive_into_static!(OsString);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::num::{Saturating, Wrapping};
+
+    /// Asserts that the static version of the type of `_val` is `S`.
+    fn assert_static<S: ?Sized + 'static, T: ?Sized + Staticize>(_val: &T) {
+        assert_eq!(T::static_type_id(), TypeId::of::<S>());
+    }
+
+    #[test]
+    fn test_staticize_control_flow_default_continue() {
+        let x = 1u8;
+        let flow: ControlFlow<&u8> = ControlFlow::Break(&x);
+        assert_static::<ControlFlow<&'static u8>, _>(&flow);
+        assert_static::<ControlFlow<&'static u8, ()>, _>(&flow);
+    }
+
+    #[test]
+    fn test_staticize_bound_pair() {
+        let (start, end) = (1u8, 2u8);
+        let range: (Bound<&u8>, Bound<&u8>) = (Bound::Included(&start), Bound::Excluded(&end));
+        assert_static::<(Bound<&'static u8>, Bound<&'static u8>), _>(&range);
+    }
+
+    derive_staticize_newtype!(#[derive(Debug, PartialEq)] struct Meters(f64));
+    derive_staticize_newtype!(struct Id<'a>(&'a str));
+
+    #[test]
+    fn test_derive_staticize_newtype() {
+        let meters = Meters(1.5);
+        assert_static::<Meters, _>(&meters);
+        assert_eq!(meters, Meters(1.5));
+
+        let name = "id";
+        let id = Id(name);
+        assert_static::<Id<'static>, _>(&id);
+        assert_eq!(id.0, "id");
+    }
+
+    #[test]
+    fn test_staticize_phantom_data_mut_ref() {
+        let marker: PhantomData<&mut u8> = PhantomData;
+        assert_static::<PhantomData<&'static mut u8>, _>(&marker);
+        let marker: PhantomData<&mut [&str]> = PhantomData;
+        assert_static::<PhantomData<&'static mut [&'static str]>, _>(&marker);
+    }
+
+    #[test]
+    fn test_staticize_byte_arrays() {
+        assert_eq!(<[u8; 0]>::static_type_id(), TypeId::of::<[u8; 0]>());
+        assert_eq!(<[u8; 1]>::static_type_id(), TypeId::of::<[u8; 1]>());
+        assert_eq!(<[u8; 32]>::static_type_id(), TypeId::of::<[u8; 32]>());
+        assert_eq!(<[u8; 64]>::static_type_id(), TypeId::of::<[u8; 64]>());
+    }
+
+    #[test]
+    fn test_staticize_ref_as_static() {
+        fn reinterpret<'a>(reference: &'a &'a u8) -> &'a &'static u8 {
+            // SAFETY: the tests only pass references to statics, which are valid for `'static`.
+            unsafe { reference.as_static() }
+        }
+
+        static VALUE: u8 = 5;
+        let reference: &u8 = &VALUE;
+        let reinterpreted = reinterpret(&reference);
+        assert!(core::ptr::eq(reinterpreted, &reference));
+        assert!(core::ptr::eq(*reinterpreted, &VALUE));
+
+        let option: Option<&str> = Some("hello");
+        // SAFETY: string literals are valid for `'static`.
+        let reinterpreted: &Option<&'static str> = unsafe { option.as_static() };
+        assert!(core::ptr::eq(reinterpreted, &option));
+        assert_eq!(*reinterpreted, Some("hello"));
+    }
+
+    #[test]
+    fn test_into_static_wrapping_and_saturating() {
+        let wrapping: Wrapping<u32> = Wrapping(5u32).into_static();
+        assert_eq!(wrapping, Wrapping(5));
+        let saturating: Saturating<u32> = Saturating(5u32).into_static();
+        assert_eq!(saturating, Saturating(5));
+    }
+
+    #[test]
+    fn test_static_dispatch_table() {
+        type Handler = fn(&dyn Any) -> &'static str;
+
+        fn handle_u8(_: &dyn Any) -> &'static str {
+            "u8"
+        }
+        fn handle_str(_: &dyn Any) -> &'static str {
+            "str"
+        }
+        fn find<T: Staticize + ?Sized>(table: &[(TypeId, Handler)], _: &T) -> Option<Handler> {
+            table
+                .iter()
+                .find(|(id, _)| *id == T::static_type_id())
+                .map(|(_, handler)| *handler)
+        }
+
+        let single: [(TypeId, Handler); 1] = static_dispatch_table!(Handler; &u8 => handle_u8);
+        assert_eq!(single[0].0, <&u8>::static_type_id());
+
+        let table = static_dispatch_table!(
+            Handler;
+            &u8 => handle_u8,
+            &str => handle_str,
+        );
+        let x = 5u8;
+        let reference: &u8 = &x;
+        let handler = find(&table, &reference).unwrap();
+        assert_eq!(handler(&x), "u8");
+        assert_eq!(find(&table, &"hello").unwrap()(&"hello"), "str");
+        assert!(find(&table, &x).is_none());
+    }
+
+    #[test]
+    fn test_into_static_bound_unbounded() {
+        // `AtomicU8` is not `Clone`, so this also checks that no `Clone` bound is required
+        let unbounded: Bound<AtomicU8> = Bound::<AtomicU8>::Unbounded.into_static();
+        assert!(matches!(unbounded, Bound::Unbounded));
+        let included: Bound<AtomicU8> = Bound::Included(AtomicU8::new(1)).into_static();
+        assert!(matches!(included, Bound::Included(ref x) if x.load(Ordering::Relaxed) == 1));
+    }
+
+    trait Marker {}
+
+    derive_staticize_dyn!(dyn Marker);
+
+    struct Borrowing<'a>(&'a u8);
+
+    impl Marker for Borrowing<'_> {}
+
+    #[test]
+    fn test_staticize_phantom_data_dyn() {
+        let marker: PhantomData<dyn Marker> = PhantomData;
+        assert_static::<PhantomData<dyn Marker + 'static>, _>(&marker);
+
+        let x = 1u8;
+        let borrowing = Borrowing(&x);
+        let object: &dyn Marker = &borrowing;
+        assert_static::<&'static (dyn Marker + 'static), _>(&object);
+        assert_eq!(*borrowing.0, 1);
+    }
+
+    #[test]
+    fn test_staticize_control_flow_unit_arms() {
+        let x = 1u8;
+        let flow: ControlFlow<(), &u8> = ControlFlow::Continue(&x);
+        assert_static::<ControlFlow<(), &'static u8>, _>(&flow);
+        let flow: ControlFlow<&u8, ()> = ControlFlow::Break(&x);
+        assert_static::<ControlFlow<&'static u8, ()>, _>(&flow);
+        let flow: ControlFlow<()> = ControlFlow::Continue(());
+        assert_static::<ControlFlow<(), ()>, _>(&flow);
+        assert_eq!(flow.into_static(), ControlFlow::Continue(()));
+    }
+
+    #[test]
+    fn test_staticize_atomic_ordering_composition() {
+        let x = 1u8;
+        let pair: (Ordering, &u8) = (Ordering::SeqCst, &x);
+        assert_static::<(Ordering, &'static u8), _>(&pair);
+        let option: Option<Ordering> = Some(Ordering::Acquire);
+        assert_static::<Option<Ordering>, _>(&option);
+        assert_eq!(option.into_static(), Some(Ordering::Acquire));
+    }
+
+    #[test]
+    fn test_staticize_slice_iter() {
+        let mut values = [1u8, 2];
+        let iter: Iter<'_, u8> = values.iter();
+        assert_static::<Iter<'static, u8>, _>(&iter);
+        let iter_mut: IterMut<'_, u8> = values.iter_mut();
+        assert_static::<IterMut<'static, u8>, _>(&iter_mut);
+    }
+
+    #[test]
+    fn test_staticize_phantom_data_fn_and_pointer() {
+        fn check<'a>(_: &'a u8) {
+            let marker: PhantomData<fn() -> &'a u8> = PhantomData;
+            assert_static::<PhantomData<fn() -> &'static u8>, _>(&marker);
+            let marker: PhantomData<*const &'a u8> = PhantomData;
+            assert_static::<PhantomData<*const &'static u8>, _>(&marker);
+        }
+        check(&1);
+
+        let marker: PhantomData<*const u8> = PhantomData;
+        assert_static::<PhantomData<*const u8>, _>(&marker);
+    }
+
+    #[test]
+    fn test_staticize_core_net() {
+        use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+        let v4 = Ipv4Addr::LOCALHOST;
+        let v6 = Ipv6Addr::LOCALHOST;
+        assert_static::<Ipv4Addr, _>(&v4);
+        assert_static::<Ipv6Addr, _>(&v6);
+        assert_static::<IpAddr, _>(&IpAddr::V4(v4));
+        assert_static::<SocketAddrV4, _>(&SocketAddrV4::new(v4, 80));
+        assert_static::<SocketAddrV6, _>(&SocketAddrV6::new(v6, 80, 0, 0));
+        assert_static::<SocketAddr, _>(&SocketAddr::new(IpAddr::V6(v6), 80));
+        let err = "not an address".parse::<IpAddr>().unwrap_err();
+        assert_static::<core::net::AddrParseError, _>(&err);
+        assert_eq!(v4.into_static(), v4);
+    }
+
+    #[test]
+    fn test_staticize_location() {
+        fn check(location: &core::panic::Location<'_>) {
+            assert_static::<core::panic::Location<'static>, _>(location);
+        }
+        check(core::panic::Location::caller());
+    }
+
+    #[test]
+    fn test_staticize_cell_ref() {
+        let cell = core::cell::RefCell::new(1u8);
+        let borrowed: core::cell::Ref<'_, u8> = cell.borrow();
+        assert_static::<core::cell::Ref<'static, u8>, _>(&borrowed);
+        drop(borrowed);
+        let borrowed_mut: core::cell::RefMut<'_, u8> = cell.borrow_mut();
+        assert_static::<core::cell::RefMut<'static, u8>, _>(&borrowed_mut);
+    }
+
+    #[test]
+    fn test_static_result_infallible() {
+        use core::convert::Infallible;
+        fn check<'a>(value: &'a u8) {
+            let result: Result<&'a u8, Infallible> = Ok(value);
+            assert_static::<StaticResult<&'a u8, Infallible>, _>(&result);
+            assert_eq!(
+                static_result_type_id::<&'a u8, Infallible>(),
+                TypeId::of::<Result<&'static u8, Infallible>>()
+            );
+        }
+        check(&1);
+    }
+
+    #[test]
+    fn test_staticize_non_null_compositions() {
+        use core::ptr::NonNull;
+        let value = 1u8;
+        let reference = &value;
+        let ptr: NonNull<&u8> = NonNull::from(&reference);
+        let option: Option<NonNull<&u8>> = Some(ptr);
+        assert_static::<Option<NonNull<&'static u8>>, _>(&option);
+        let tuple: (NonNull<&u8>, NonNull<u8>) = (ptr, NonNull::from(&value));
+        assert_static::<(NonNull<&'static u8>, NonNull<u8>), _>(&tuple);
+    }
+
+    #[test]
+    #[allow(clippy::type_complexity)]
+    fn test_staticize_phantom_data_nested() {
+        fn check<'a>(_: &'a u8) {
+            let marker: PhantomData<(&'a u8, [&'a str; 2])> = PhantomData;
+            assert_static::<PhantomData<(&'static u8, [&'static str; 2])>, _>(&marker);
+            let marker: PhantomData<Option<(&'a [u8], Result<&'a u8, ()>)>> = PhantomData;
+            assert_static::<PhantomData<Option<(&'static [u8], Result<&'static u8, ()>)>>, _>(
+                &marker,
+            );
+            let marker: PhantomData<PhantomData<&'a u8>> = PhantomData;
+            assert_static::<PhantomData<PhantomData<&'static u8>>, _>(&marker);
+        }
+        check(&1);
+    }
+
+    #[test]
+    fn test_staticize_future_ready() {
+        let value = 1u8;
+        let ready: core::future::Ready<&u8> = core::future::ready(&value);
+        assert_static::<core::future::Ready<&'static u8>, _>(&ready);
+    }
+
+    #[test]
+    fn test_staticize_rev_slice_iter() {
+        let values = [1u8, 2];
+        let rev: core::iter::Rev<Iter<'_, u8>> = values.iter().rev();
+        assert_static::<core::iter::Rev<Iter<'static, u8>>, _>(&rev);
+    }
+
+    #[test]
+    fn test_staticize_comparator_fn() {
+        fn check<'a>(_: &'a u8) {
+            let compare: fn(&'a u8, &'a u8) -> core::cmp::Ordering = |a, b| a.cmp(b);
+            assert_static::<fn(&'static u8, &'static u8) -> core::cmp::Ordering, _>(&compare);
+        }
+        check(&1);
+    }
+
+    #[test]
+    fn test_staticize_maybe_uninit_array() {
+        use core::mem::MaybeUninit;
+        let value = 1u8;
+        let mut slots: [MaybeUninit<&u8>; 4] = [MaybeUninit::uninit(); 4];
+        slots[0].write(&value);
+        assert_static::<[MaybeUninit<&'static u8>; 4], _>(&slots);
+    }
+
+    #[test]
+    fn test_staticize_map_slice_iter_fn_pointer() {
+        fn check<'a>(values: &'a [u8]) {
+            let double: fn(&'a u8) -> u8 = |x| x * 2;
+            let map: core::iter::Map<Iter<'a, u8>, fn(&'a u8) -> u8> = values.iter().map(double);
+            assert_static::<core::iter::Map<Iter<'static, u8>, fn(&'static u8) -> u8>, _>(&map);
+            assert_eq!(map.sum::<u8>(), 6);
+        }
+        check(&[1, 2]);
+    }
+
+    #[test]
+    fn test_atomic_ptr_into_static_preserves_pointer() {
+        static VALUE: u8 = 1;
+        let mut reference: &u8 = &VALUE;
+        let raw: *mut &u8 = &mut reference;
+        // SAFETY: the pointee outlives every use of the returned pointer below.
+        let converted: AtomicPtr<&'static u8> =
+            unsafe { atomic_ptr_into_static(AtomicPtr::new(raw)) };
+        let converted = converted.into_inner();
+        assert_eq!(converted.cast::<&u8>(), raw);
+        // SAFETY: `converted` still points at `reference`, which borrows a `static`.
+        assert_eq!(unsafe { **converted }, 1);
+
+        let null: AtomicPtr<&u8> = AtomicPtr::new(core::ptr::null_mut());
+        // SAFETY: the pointer is null.
+        let converted = unsafe { atomic_ptr_into_static(null) };
+        assert!(converted.into_inner().is_null());
+    }
+
+    #[test]
+    fn test_staticize_type_id() {
+        let id = TypeId::of::<u8>();
+        assert_static::<TypeId, _>(&id);
+        assert_eq!(TypeId::static_type_id(), TypeId::of::<TypeId>());
+    }
+
+    #[cfg(feature = "alloc")]
+    mod alloc_tests {
+        use super::*;
+        use alloc::{
+            boxed::Box,
+            collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
+            ffi::CString,
+            string::String,
+            sync::Arc,
+            vec,
+            vec::Vec,
+        };
+        use core::{
+            cell::{Cell, RefCell},
+            cmp::Reverse,
+        };
+
+        #[test]
+        fn test_try_into_static_cow_owned() {
+            let cow: Cow<'_, str> = Cow::Owned(String::from("hello"));
+            let converted: Cow<'static, str> = cow.clone().try_into_static().unwrap();
+            assert!(matches!(converted, Cow::Owned(ref s) if s == "hello"));
+            let converted = cow.try_into_static_with(BorrowPolicy::Clone).unwrap();
+            assert!(matches!(converted, Cow::Owned(ref s) if s == "hello"));
+        }
+
+        #[test]
+        fn test_try_into_static_cow_borrowed() {
+            let owned = String::from("hello");
+            let cow: Cow<'_, str> = Cow::Borrowed(&owned);
+            assert_eq!(cow.clone().try_into_static(), Err("hello"));
+            assert_eq!(
+                cow.clone().try_into_static_with(BorrowPolicy::Reject),
+                Err("hello")
+            );
+            let converted: Cow<'static, str> =
+                cow.try_into_static_with(BorrowPolicy::Clone).unwrap();
+            assert!(matches!(converted, Cow::Owned(ref s) if s == "hello"));
+        }
+
+        #[test]
+        fn test_staticize_cstring() {
+            assert_eq!(CString::static_type_id(), TypeId::of::<CString>());
+            #[cfg(feature = "std")]
+            assert_eq!(std::ffi::CString::static_type_id(), TypeId::of::<CString>());
+        }
+
+        #[test]
+        fn test_leak_to_static_string() {
+            let leaked: String = leak_to_static(String::from("hello"));
+            assert_eq!(leaked, "hello");
+            let owned = String::from("hello");
+            let leaked: &'static String = leak_to_static(&owned);
+            assert_eq!(leaked, &owned);
+            assert!(!core::ptr::eq(leaked, &owned));
+        }
+
+        #[test]
+        fn test_leak_to_static_vec() {
+            let leaked: Vec<u8> = leak_to_static(vec![1u8, 2, 3]);
+            assert_eq!(leaked, [1, 2, 3]);
+            let owned = vec![1u8, 2, 3];
+            let leaked: &'static Vec<u8> = leak_to_static(&owned);
+            assert_eq!(leaked, &owned);
+            let leaked: &'static [u8] = leak_to_static(owned.as_slice());
+            assert_eq!(leaked, [1, 2, 3]);
+        }
+
+        #[test]
+        fn test_into_static_bound() {
+            let x = 5u8;
+            let included: Bound<&'static u8> = Bound::Included(&x).into_static();
+            assert_eq!(included, Bound::Included(&5));
+            let excluded: Bound<&'static u8> = Bound::Excluded(&x).into_static();
+            assert_eq!(excluded, Bound::Excluded(&5));
+            let unbounded: Bound<&'static u8> = Bound::<&u8>::Unbounded.into_static();
+            assert_eq!(unbounded, Bound::Unbounded);
+        }
+
+        #[test]
+        fn test_staticize_arc_unsized() {
+            let (a, b) = (1u8, 2u8);
+            let slice: Arc<[&u8]> = Arc::from([&a, &b]);
+            assert_static::<Arc<[&'static u8]>, _>(&slice);
+            let converted: Arc<[&'static u8]> = slice.into_static();
+            assert_eq!(*converted, [&1, &2]);
+            let string: Arc<str> = Arc::from("hello");
+            assert_static::<Arc<str>, _>(&string);
+            let converted: Arc<str> = string.clone().into_static();
+            assert!(Arc::ptr_eq(&converted, &string));
+        }
+
+        #[test]
+        fn test_into_static_arc_unique() {
+            let x = 5u8;
+            let arc: Arc<&u8> = Arc::new(&x);
+            let converted: Arc<&'static u8> = arc.into_static();
+            assert_eq!(**converted, 5);
+            assert_eq!(Arc::strong_count(&converted), 1);
+        }
+
+        #[test]
+        fn test_into_static_arc_shared() {
+            let arc = Arc::new(String::from("hello"));
+            let other = Arc::clone(&arc);
+            assert_eq!(Arc::strong_count(&other), 2);
+            let converted: Arc<String> = arc.into_static();
+            assert_eq!(*converted, "hello");
+            assert!(!Arc::ptr_eq(&converted, &other));
+            assert_eq!(Arc::strong_count(&converted), 1);
+            assert_eq!(Arc::strong_count(&other), 1);
+        }
+
+        #[test]
+        fn test_static_box() {
+            fn store<'a>(_: &'a u8) -> StaticBox {
+                StaticBox::new::<&'a u8>(&5)
+            }
+            fn recover<'b>(boxed: &StaticBox, _: &'b u8) -> Option<&'static u8> {
+                boxed.get::<&'b u8>().copied()
+            }
+
+            let a = 1u8;
+            let boxed = store(&a);
+            let b = 2u8;
+            assert_eq!(recover(&boxed, &b), Some(&5));
+            assert_eq!(boxed.type_name(), <&u8>::static_type_name());
+            assert_eq!(boxed.get::<&u16>(), None);
+            assert_eq!(boxed.get::<u8>(), None);
+        }
+
+        #[test]
+        fn test_into_static_btree_map() {
+            let keys = [String::from("b"), String::from("c"), String::from("a")];
+            let map: BTreeMap<&str, u32> = keys.iter().map(String::as_str).zip(1..).collect();
+            let converted: BTreeMap<&'static str, u32> = map.clone().into_static();
+            assert!(converted.iter().eq(map.iter()));
+            assert_eq!(
+                converted.keys().copied().collect::<Vec<_>>(),
+                ["a", "b", "c"]
+            );
+        }
+
+        #[test]
+        fn test_staticize_binary_heap_reverse() {
+            let x = 1u8;
+            let heap: BinaryHeap<Reverse<&u8>> = BinaryHeap::from([Reverse(&x)]);
+            assert_static::<BinaryHeap<Reverse<&'static u8>>, _>(&heap);
+
+            let mut heap: <BinaryHeap<Reverse<&u8>> as Staticize>::Static = BinaryHeap::new();
+            heap.extend([Reverse(&3), Reverse(&1), Reverse(&2)]);
+            assert_eq!(heap.pop(), Some(Reverse(&1)));
+            assert_eq!(heap.pop(), Some(Reverse(&2)));
+            assert_eq!(heap.pop(), Some(Reverse(&3)));
+            assert_eq!(heap.pop(), None);
+        }
+
+        #[test]
+        fn test_into_static_control_flow() {
+            let (x, y) = (1u8, 2u8);
+            let flow: ControlFlow<&u8, &u8> = ControlFlow::Continue(&x);
+            let converted: ControlFlow<&'static u8, &'static u8> = flow.into_static();
+            assert_eq!(converted, ControlFlow::Continue(&1));
+            let flow: ControlFlow<&u8, &u8> = ControlFlow::Break(&y);
+            let converted: ControlFlow<&'static u8, &'static u8> = flow.into_static();
+            assert_eq!(converted, ControlFlow::Break(&2));
+        }
+
+        #[test]
+        fn test_into_static_cell() {
+            let x = 5u8;
+            let cell: Cell<&u8> = Cell::new(&x);
+            let converted: Cell<&'static u8> = cell.into_static();
+            assert_eq!(*converted.get(), 5);
+        }
+
+        #[test]
+        fn test_into_static_option() {
+            let x = 5u8;
+            let some: Option<&'static u8> = Some(&x).into_static();
+            assert_eq!(some, Some(&5));
+            let none: Option<&'static u8> = None::<&u8>.into_static();
+            assert_eq!(none, None);
+        }
+
+        #[test]
+        fn test_into_static_result() {
+            let (x, message) = (5u8, String::from("error"));
+            let ok: Result<&u8, &str> = Ok(&x);
+            let converted: Result<&'static u8, &'static str> = ok.into_static();
+            assert_eq!(converted, Ok(&5));
+            let err: Result<&u8, &str> = Err(&message);
+            let converted: Result<&'static u8, &'static str> = err.into_static();
+            assert_eq!(converted, Err("error"));
+        }
+
+        #[test]
+        fn test_into_static_tuple() {
+            let (string, x, values) = (String::from("a"), 5u32, [1u8, 2]);
+            let tuple: (&str, &u32, &[u8]) = (&string, &x, &values);
+            let converted: (&'static str, &'static u32, &'static [u8]) = tuple.into_static();
+            assert_eq!(converted, ("a", &5, &[1, 2][..]));
+        }
+
+        #[test]
+        fn test_into_static_vec() {
+            let copied: Vec<u32> = vec![1u32, 2, 3].into_static();
+            assert_eq!(copied, [1, 2, 3]);
+            let strings = [String::from("a"), String::from("b")];
+            let borrowed: Vec<&str> = strings.iter().map(String::as_str).collect();
+            let leaked: Vec<&'static str> = borrowed.into_static();
+            assert_eq!(leaked, ["a", "b"]);
+        }
+
+        #[test]
+        fn test_into_static_ranges() {
+            let (start, end) = (1u8, 5u8);
+            let range: core::ops::Range<&'static u8> = (&start..&end).into_static();
+            assert_eq!(range, &1..&5);
+            let range: core::ops::RangeInclusive<&'static u8> = (&start..=&end).into_static();
+            assert_eq!(range, &1..=&5);
+            let range: core::ops::RangeFrom<&'static u8> = (&start..).into_static();
+            assert_eq!(range, &1..);
+            let range: core::ops::RangeTo<&'static u8> = (..&end).into_static();
+            assert_eq!(range, ..&5);
+            let range: core::ops::RangeToInclusive<&'static u8> = (..=&end).into_static();
+            assert_eq!(range, ..=&5);
+        }
+
+        #[test]
+        fn test_into_static_wrapping_ref() {
+            let x = 5u8;
+            let wrapping: Wrapping<&'static u8> = Wrapping(&x).into_static();
+            assert_eq!(wrapping.0, &5);
+            let saturating: Saturating<&'static u8> = Saturating(&x).into_static();
+            assert_eq!(saturating.0, &5);
+        }
+
+        #[test]
+        fn test_into_static_box() {
+            let moved: Box<u8> = Box::new(5u8).into_static();
+            assert_eq!(*moved, 5);
+            let string = String::from("hello");
+            let leaked: Box<&'static str> = Box::new(string.as_str()).into_static();
+            assert_eq!(*leaked, "hello");
+        }
+
+        #[test]
+        fn test_into_static_cow() {
+            let string = String::from("hello");
+            let converted: Cow<'static, str> = Cow::Borrowed(string.as_str()).into_static();
+            assert!(matches!(converted, Cow::Owned(ref s) if s == "hello"));
+            let converted: Cow<'static, str> = Cow::<str>::Owned(string.clone()).into_static();
+            assert!(matches!(converted, Cow::Owned(ref s) if s == "hello"));
+
+            let values = vec![1u8, 2];
+            let converted: Cow<'static, [u8]> = Cow::Borrowed(values.as_slice()).into_static();
+            assert!(matches!(converted, Cow::Owned(ref v) if *v == [1, 2]));
+            let converted: Cow<'static, [u8]> = Cow::<[u8]>::Owned(values.clone()).into_static();
+            assert!(matches!(converted, Cow::Owned(ref v) if *v == [1, 2]));
+        }
+
+        #[test]
+        fn test_into_static_vec_deque_and_linked_list() {
+            let (a, b, c) = (1u8, 2u8, 3u8);
+            let mut deque: VecDeque<&u8> = VecDeque::from([&b, &c]);
+            deque.push_front(&a);
+            let converted: VecDeque<&'static u8> = deque.into_static();
+            assert!(converted.iter().copied().eq([&1, &2, &3]));
+
+            let list: LinkedList<String> = ["a", "b"].into_iter().map(String::from).collect();
+            let converted: LinkedList<String> = list.into_static();
+            assert!(converted.iter().eq(["a", "b"]));
+        }
+
+        #[test]
+        fn test_into_static_btree_set() {
+            let strings = [String::from("b"), String::from("c"), String::from("a")];
+            let set: BTreeSet<&str> = strings.iter().map(String::as_str).collect();
+            let converted: BTreeSet<&'static str> = set.into_static();
+            assert!(converted.iter().copied().eq(["a", "b", "c"]));
+        }
+
+        #[test]
+        fn test_into_static_bound_unbounded_ref() {
+            let unbounded: Bound<&'static u8> = Bound::<&u8>::Unbounded.into_static();
+            assert_eq!(unbounded, Bound::Unbounded);
+        }
+
+        #[test]
+        fn test_into_static_ref_cell() {
+            let strings = [String::from("a"), String::from("b")];
+            let cell: RefCell<Vec<&str>> =
+                RefCell::new(strings.iter().map(String::as_str).collect());
+            let converted: RefCell<Vec<&'static str>> = cell.into_static();
+            assert_eq!(*converted.borrow(), ["a", "b"]);
+        }
+
+        #[test]
+        fn test_into_static_unsized_box() {
+            let string: Box<str> = Box::from("hello");
+            let address = string.as_ptr();
+            let converted: Box<str> = string.into_static();
+            assert_eq!(&*converted, "hello");
+            assert_eq!(converted.as_ptr(), address);
+
+            let (a, b) = (1u8, 2u8);
+            let slice: Box<[&u8]> = Box::new([&a, &b]);
+            let converted: Box<[&'static u8]> = slice.into_static();
+            assert_eq!(*converted, [&1, &2]);
+        }
+
+        #[test]
+        fn test_into_static_atomic_ordering_pair() {
+            let x = 1u8;
+            let converted: (Ordering, &'static u8) = (Ordering::SeqCst, &x).into_static();
+            assert_eq!(converted, (Ordering::SeqCst, &1));
+        }
+
+        #[test]
+        fn test_staticize_box_dyn() {
+            let value = 1u8;
+            let boxed: Box<dyn Marker + '_> = Box::new(Borrowing(&value));
+            assert_static::<Box<dyn Marker + 'static>, _>(&boxed);
+        }
+
+        #[test]
+        fn test_downcast_static_ref_boxed() {
+            fn lookup<'a>(stored: &dyn Any, _key: &'a u32) -> Option<&'static u32> {
+                downcast_static_ref::<&'a u32>(stored).copied()
+            }
+            static VALUE: u32 = 7;
+            let stored: Box<dyn Any> = Box::new(&VALUE);
+            let local = 0u32;
+            assert_eq!(lookup(&*stored, &local), Some(&7));
+            let other: Box<dyn Any> = Box::new(7u32);
+            assert_eq!(lookup(&*other, &local), None);
+        }
+
+        #[test]
+        fn test_staticize_boxed_slice_of_refs() {
+            let value = 1u8;
+            let boxed: Box<[&u8]> = vec![&value].into_boxed_slice();
+            assert_static::<Box<[&'static u8]>, _>(&boxed);
+            let nested: Vec<Box<[&u8]>> = vec![boxed];
+            assert_static::<Vec<Box<[&'static u8]>>, _>(&nested);
+        }
+
+        #[test]
+        fn test_btree_map_static_is_usable() {
+            fn build<'a>(
+                _: &BTreeMap<&'a str, u8>,
+            ) -> <BTreeMap<&'a str, u8> as Staticize>::Static {
+                let mut map = <BTreeMap<&'a str, u8> as Staticize>::Static::new();
+                map.insert("b", 2);
+                map.insert("a", 1);
+                map
+            }
+            let owned = String::from("a");
+            let local: BTreeMap<&str, u8> = [(owned.as_str(), 1)].into_iter().collect();
+            let map: BTreeMap<&'static str, u8> = build(&local);
+            assert_eq!(
+                map.into_iter().collect::<Vec<_>>(),
+                vec![("a", 1), ("b", 2)]
+            );
+        }
+
+        #[test]
+        fn test_staticize_cow_c_str() {
+            use core::ffi::CStr;
+            let owned = CString::new("name").unwrap();
+            let cow: Cow<'_, CStr> = Cow::Borrowed(owned.as_c_str());
+            assert_static::<Cow<'static, CStr>, _>(&cow);
+            let converted: Cow<'static, CStr> = cow.into_static();
+            assert!(matches!(converted, Cow::Owned(ref c) if c.as_c_str() == owned.as_c_str()));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod std_tests {
+        use super::*;
+        use std::collections::{HashMap, HashSet};
+
+        #[test]
+        fn test_static_type_registry() {
+            fn insert<'a>(registry: &mut StaticTypeRegistry<&'static str>, _: &'a u8) {
+                assert_eq!(registry.insert::<&'a u8>("u8"), None);
+            }
+            fn get<'b>(
+                registry: &StaticTypeRegistry<&'static str>,
+                _: &'b u8,
+            ) -> Option<&'static str> {
+                assert!(registry.contains::<&'b u8>());
+                registry.get::<&'b u8>().copied()
+            }
+
+            let mut registry = StaticTypeRegistry::new();
+            let a = 1u8;
+            insert(&mut registry, &a);
+            let b = 2u8;
+            assert_eq!(get(&registry, &b), Some("u8"));
+            assert!(!registry.contains::<&u16>());
+            assert_eq!(registry.get::<u8>(), None);
+        }
+
+        #[test]
+        #[allow(clippy::type_complexity)]
+        fn test_staticize_deeply_nested() {
+            let (key, val) = (String::from("key"), 5u8);
+            let mut map: HashMap<&str, Vec<Option<&u8>>> = HashMap::new();
+            map.insert(&key, vec![Some(&val), None]);
+            assert_static::<HashMap<&'static str, Vec<Option<&'static u8>>>, _>(&map);
+
+            let nested: Result<Vec<(&str, Option<&u8>)>, HashMap<&str, Box<[&u8]>>> =
+                Ok(vec![(&key, Some(&val))]);
+            assert_static::<
+                Result<
+                    Vec<(&'static str, Option<&'static u8>)>,
+                    HashMap<&'static str, Box<[&'static u8]>>,
+                >,
+                _,
+            >(&nested);
+
+            let deeper: Option<(Vec<Result<&u8, &str>>, HashMap<u32, Vec<Vec<&str>>>)> = None;
+            assert_static::<
+                Option<(
+                    Vec<Result<&'static u8, &'static str>>,
+                    HashMap<u32, Vec<Vec<&'static str>>>,
+                )>,
+                _,
+            >(&deeper);
+
+            let converted: HashMap<&'static str, Vec<Option<&'static u8>>> = map.into_static();
+            assert_eq!(converted["key"], [Some(&5), None]);
+        }
+
+        #[test]
+        fn test_staticize_cow_path_and_os_str() {
+            let buf = PathBuf::from("/tmp");
+            let path: Cow<'_, Path> = Cow::Borrowed(&buf);
+            assert_static::<Cow<'static, Path>, _>(&path);
+            let converted: Cow<'static, Path> = path.into_static();
+            assert_eq!(converted, Path::new("/tmp"));
+
+            let string = OsString::from("tmp");
+            let os_str: Cow<'_, OsStr> = Cow::Borrowed(&string);
+            assert_static::<Cow<'static, OsStr>, _>(&os_str);
+            let converted: Cow<'static, OsStr> = os_str.into_static();
+            assert_eq!(converted, OsStr::new("tmp"));
+        }
+
+        #[test]
+        fn test_static_type_registry_insert_with() {
+            fn insert_with<'a>(
+                registry: &mut StaticTypeRegistry<u32>,
+                _: &'a u8,
+                calls: &mut u32,
+            ) -> u32 {
+                *registry.insert_with::<&'a u8>(|| {
+                    *calls += 1;
+                    *calls
+                })
+            }
+
+            let mut registry = StaticTypeRegistry::new();
+            let mut calls = 0;
+            let a = 1u8;
+            assert_eq!(insert_with(&mut registry, &a, &mut calls), 1);
+            let b = 2u8;
+            assert_eq!(insert_with(&mut registry, &b, &mut calls), 1);
+            assert_eq!(calls, 1);
+
+            *registry.entry::<&u8>().or_insert(0) += 10;
+            assert_eq!(registry.get::<&'static u8>(), Some(&11));
+            registry.entry::<u8>().or_insert(7);
+            assert_eq!(registry.get::<u8>(), Some(&7));
+        }
+
+        #[test]
+        fn test_into_static_hash_map() {
+            let keys = [String::from("a"), String::from("b")];
+            let map: HashMap<&str, u32> = keys.iter().map(String::as_str).zip(1..).collect();
+            let converted: HashMap<&'static str, u32> = map.into_static();
+            assert_eq!(converted.len(), 2);
+            assert_eq!(converted.get("a"), Some(&1));
+            assert_eq!(converted.get("b"), Some(&2));
+            assert_eq!(converted.get("c"), None);
+        }
+
+        #[test]
+        fn test_into_static_hash_set() {
+            let strings = [String::from("a"), String::from("b")];
+            let set: HashSet<&str> = strings.iter().map(String::as_str).collect();
+            let converted: HashSet<&'static str> = set.into_static();
+            assert_eq!(converted.len(), 2);
+            assert!(converted.contains("a"));
+            assert!(converted.contains("b"));
+            assert!(!converted.contains("c"));
+        }
+
+        static HASHER_BUILDS: AtomicUsize = AtomicUsize::new(0);
+
+        /// Counts every hasher it builds, i.e. every time a key is hashed.
+        #[derive(Default)]
+        struct CountingState;
+
+        impl core::hash::BuildHasher for CountingState {
+            type Hasher = std::hash::DefaultHasher;
+
+            fn build_hasher(&self) -> Self::Hasher {
+                HASHER_BUILDS.fetch_add(1, Ordering::Relaxed);
+                std::hash::DefaultHasher::new()
+            }
+        }
+
+        derive_staticize!(CountingState);
+
+        #[test]
+        fn test_into_static_hash_map_and_set_do_not_rehash() {
+            let keys: Vec<String> = (0..100).map(|i| i.to_string()).collect();
+
+            let map: HashMap<&str, usize, CountingState> =
+                keys.iter().map(String::as_str).zip(0..).collect();
+            HASHER_BUILDS.store(0, Ordering::Relaxed);
+            let converted: HashMap<&'static str, usize, CountingState> = map.into_static();
+            assert_eq!(converted.len(), keys.len());
+            // growing the table would hash every entry it moves a second time
+            assert_eq!(HASHER_BUILDS.load(Ordering::Relaxed), keys.len());
+
+            let set: HashSet<&str, CountingState> = keys.iter().map(String::as_str).collect();
+            HASHER_BUILDS.store(0, Ordering::Relaxed);
+            let converted: HashSet<&'static str, CountingState> = set.into_static();
+            assert_eq!(converted.len(), keys.len());
+            assert_eq!(HASHER_BUILDS.load(Ordering::Relaxed), keys.len());
+        }
+
+        #[test]
+        fn test_staticize_mpsc_channels() {
+            let value = 1u8;
+            let (sender, receiver) = std::sync::mpsc::channel::<&u8>();
+            sender.send(&value).unwrap();
+            assert_static::<std::sync::mpsc::Sender<&'static u8>, _>(&sender);
+            assert_static::<std::sync::mpsc::Receiver<&'static u8>, _>(&receiver);
+            let (sync_sender, _receiver) = std::sync::mpsc::sync_channel::<&u8>(1);
+            sync_sender.send(&value).unwrap();
+            assert_static::<std::sync::mpsc::SyncSender<&'static u8>, _>(&sync_sender);
+        }
+
+        #[test]
+        fn test_staticize_hash_set_of_str() {
+            let owned = String::from("a");
+            let mut set: HashSet<&str> = HashSet::new();
+            set.insert(owned.as_str());
+            assert_static::<HashSet<&'static str, std::hash::RandomState>, _>(&set);
+        }
+
+        #[test]
+        fn test_staticize_arc_locks() {
+            use std::sync::{Arc, Mutex, RwLock};
+            let value = 1u8;
+            let owned = String::from("a");
+            let mutex: Arc<Mutex<&u8>> = Arc::new(Mutex::new(&value));
+            assert_static::<Arc<Mutex<&'static u8>>, _>(&mutex);
+            let rw_lock: Arc<RwLock<Vec<&str>>> = Arc::new(RwLock::new(vec![owned.as_str()]));
+            assert_static::<Arc<RwLock<Vec<&'static str>>>, _>(&rw_lock);
+        }
+
+        #[test]
+        fn test_staticize_hash_map_arc_str_keys() {
+            use std::sync::Arc;
+            let value = 1u8;
+            let mut map: HashMap<Arc<str>, &u8> = HashMap::new();
+            map.insert(Arc::from("a"), &value);
+            assert_static::<HashMap<Arc<str>, &'static u8>, _>(&map);
+        }
+
+        #[test]
+        fn test_staticize_buf_reader_cursor() {
+            let bytes = [1u8, 2, 3];
+            let reader = std::io::BufReader::new(std::io::Cursor::new(&bytes[..]));
+            assert_static::<std::io::BufReader<std::io::Cursor<&'static [u8]>>, _>(&reader);
+        }
+
+        #[test]
+        fn test_staticize_build_hasher_default() {
+            use std::hash::{BuildHasherDefault, DefaultHasher};
+            let build_hasher: BuildHasherDefault<DefaultHasher> = BuildHasherDefault::default();
+            assert_static::<BuildHasherDefault<DefaultHasher>, _>(&build_hasher);
+            let value = 1u8;
+            let mut map: HashMap<&u8, u8, BuildHasherDefault<DefaultHasher>> = HashMap::default();
+            map.insert(&value, 2);
+            assert_static::<HashMap<&'static u8, u8, BuildHasherDefault<DefaultHasher>>, _>(&map);
+        }
+
+        #[test]
+        fn test_staticize_join_handle() {
+            use std::thread::JoinHandle;
+            fn check<'a>(_: &'a u8) {
+                assert_eq!(
+                    <JoinHandle<&'a u8> as Staticize>::static_type_id(),
+                    TypeId::of::<JoinHandle<&'static u8>>()
+                );
+            }
+            check(&1);
+            static VALUE: u8 = 1;
+            let handle = std::thread::spawn(|| &VALUE);
+            assert_static::<JoinHandle<&'static u8>, _>(&handle);
+            assert_eq!(*handle.join().unwrap(), 1);
+        }
+
+        #[test]
+        fn test_into_static_path_and_os_str_refs() {
+            let owned = PathBuf::from("some/dir/file.txt");
+            let path: &Path = owned.as_path();
+            let leaked: &'static Path = path.into_static();
+            assert_eq!(leaked, path);
+            assert_ne!(
+                leaked.as_os_str().as_encoded_bytes().as_ptr(),
+                path.as_os_str().as_encoded_bytes().as_ptr()
+            );
+
+            let owned = OsString::from("name");
+            let os_str: &OsStr = owned.as_os_str();
+            let leaked: &'static OsStr = os_str.into_static();
+            assert_eq!(leaked, os_str);
+        }
+
+        #[test]
+        fn test_staticize_duration_compositions() {
+            use core::time::Duration;
+            let owned = String::from("timeout");
+            let value = 1u8;
+            let pair: (Duration, &str) = (Duration::from_secs(1), owned.as_str());
+            assert_static::<(Duration, &'static str), _>(&pair);
+            let mut map: HashMap<Duration, &u8> = HashMap::new();
+            map.insert(Duration::from_millis(5), &value);
+            assert_static::<HashMap<Duration, &'static u8>, _>(&map);
+        }
+
+        #[test]
+        fn test_staticize_std_net_sockets() {
+            use std::net::{TcpListener, TcpStream, UdpSocket};
+            assert_eq!(TcpStream::static_type_id(), TypeId::of::<TcpStream>());
+            assert_eq!(TcpListener::static_type_id(), TypeId::of::<TcpListener>());
+            assert_eq!(UdpSocket::static_type_id(), TypeId::of::<UdpSocket>());
+        }
+    }
+}